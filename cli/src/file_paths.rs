@@ -18,11 +18,52 @@ impl From<HomeDirError> for io::Error {
 }
 
 fn get_home_dir() -> Result<path::PathBuf, HomeDirError> {
-    let home_dir = match home::home_dir() {
-        Some(home_dir) => home_dir,
-        None => return Err(HomeDirError),
-    };
-    Ok(home_dir)
+    if let Some(home_dir) = home::home_dir() {
+        return Ok(home_dir);
+    }
+
+    #[cfg(unix)]
+    if let Some(home_dir) = get_home_dir_from_passwd_db() {
+        return Ok(home_dir);
+    }
+
+    Err(HomeDirError)
+}
+
+// Fallback for environments where `$HOME` (and platform user APIs) are
+// unavailable, e.g. daemons or shells launched without `HOME` set.
+#[cfg(unix)]
+#[allow(unsafe_code)]
+fn get_home_dir_from_passwd_db() -> Option<path::PathBuf> {
+    use std::os::unix::ffi::OsStringExt;
+
+    unsafe {
+        let uid = libc::getuid();
+
+        let buflen = match libc::sysconf(libc::_SC_GETPW_R_SIZE_MAX) {
+            -1 => 16384,
+            n => n as usize,
+        };
+        let mut buf = vec![0_u8; buflen];
+
+        let mut passwd: libc::passwd = std::mem::zeroed();
+        let mut result: *mut libc::passwd = std::ptr::null_mut();
+
+        let ret = libc::getpwuid_r(
+            uid,
+            &mut passwd,
+            buf.as_mut_ptr().cast(),
+            buf.len(),
+            &mut result,
+        );
+
+        if ret != 0 || result.is_null() || passwd.pw_dir.is_null() {
+            return None;
+        }
+
+        let pw_dir = ffi::CStr::from_ptr(passwd.pw_dir).to_bytes().to_vec();
+        Some(path::PathBuf::from(ffi::OsString::from_vec(pw_dir)))
+    }
 }
 
 fn get_config_dir() -> Result<path::PathBuf, HomeDirError> {
@@ -31,6 +72,13 @@ fn get_config_dir() -> Result<path::PathBuf, HomeDirError> {
         return Ok(path::PathBuf::from(env_var_config_dir));
     }
 
+    // on Windows, use the roaming AppData known folder
+    #[cfg(windows)]
+    if let Some(mut res) = get_known_folder(&winapi::um::knownfolders::FOLDERID_RoamingAppData) {
+        res.push("fend");
+        return Ok(res);
+    }
+
     // otherwise try $XDG_CONFIG_HOME/fend/
     if let Some(env_var_xdg_config_dir) = env::var_os("XDG_CONFIG_HOME") {
         let mut res = path::PathBuf::from(env_var_xdg_config_dir);
@@ -57,6 +105,14 @@ fn get_state_dir() -> Result<path::PathBuf, HomeDirError> {
         return Ok(path::PathBuf::from(env_var_history_dir));
     }
 
+    // on Windows, use the local AppData known folder
+    #[cfg(windows)]
+    if let Some(mut res) = get_known_folder(&winapi::um::knownfolders::FOLDERID_LocalAppData) {
+        res.push("fend");
+        res.push("state");
+        return Ok(res);
+    }
+
     // otherwise try $XDG_STATE_HOME/fend/
     if let Some(env_var_xdg_state_dir) = env::var_os("XDG_STATE_HOME") {
         let mut res = path::PathBuf::from(env_var_xdg_state_dir);
@@ -92,6 +148,13 @@ pub fn get_cache_dir() -> Result<path::PathBuf, HomeDirError> {
         return Ok(path::PathBuf::from(env_var_cache_dir));
     }
 
+    // on Windows, use the local AppData known folder
+    #[cfg(windows)]
+    if let Some(mut res) = get_known_folder(&winapi::um::knownfolders::FOLDERID_LocalAppData) {
+        res.push("fend");
+        return Ok(res);
+    }
+
     // otherwise try $XDG_CACHE_HOME/fend/
     if let Some(env_var_xdg_cache_dir) = env::var_os("XDG_CACHE_HOME") {
         let mut res = path::PathBuf::from(env_var_xdg_cache_dir);
@@ -108,6 +171,32 @@ pub fn get_cache_dir() -> Result<path::PathBuf, HomeDirError> {
     Ok(res)
 }
 
+// Resolve a Windows "known folder" (e.g. roaming/local AppData), which is
+// the idiomatic, profile-aware way to locate per-user app directories,
+// instead of emulating XDG paths underneath the home directory.
+#[cfg(windows)]
+#[allow(unsafe_code)]
+fn get_known_folder(folder_id: &winapi::shared::guiddef::GUID) -> Option<path::PathBuf> {
+    use std::os::windows::ffi::OsStringExt;
+    use winapi::um::{combaseapi::CoTaskMemFree, shlobj::SHGetKnownFolderPath};
+
+    unsafe {
+        let mut path_ptr = std::ptr::null_mut();
+        let hr = SHGetKnownFolderPath(folder_id, 0, std::ptr::null_mut(), &mut path_ptr);
+        if hr != 0 {
+            return None;
+        }
+
+        let len = (0..).take_while(|&i| *path_ptr.offset(i) != 0).count();
+        let slice = std::slice::from_raw_parts(path_ptr, len);
+        let result = path::PathBuf::from(ffi::OsString::from_wide(slice));
+
+        CoTaskMemFree(path_ptr.cast());
+
+        Some(result)
+    }
+}
+
 pub fn create_cache_dir() -> io::Result<path::PathBuf> {
     let cache_dir = get_cache_dir()?;
     fs::create_dir_all(&cache_dir)?;
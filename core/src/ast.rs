@@ -28,6 +28,16 @@ pub enum Expr {
     ApplyMul(Box<Expr>, Box<Expr>),
 
     As(Box<Expr>, Box<Expr>),
+
+    // Bind a name to the value of an expression in the current scope,
+    // e.g. `x = 5`, and evaluate to that value.
+    //
+    // TODO: the parser does not yet produce this variant (`ident = expr`
+    // needs a production in `parser`), so it's currently unreachable from
+    // user input. Function assignment (`f = x => x^2`, an `Expr::FnDef`)
+    // is not implemented at all: it additionally needs a closure-carrying
+    // `Value` variant, since `Value::Func` only names built-ins.
+    Assign(String, Box<Expr>),
 }
 
 impl Debug for Expr {
@@ -50,6 +60,7 @@ impl Debug for Expr {
                 write!(f, "({:?} {:?})", *a, *b)
             }
             Self::As(a, b) => write!(f, "({:?} as {:?})", *a, *b),
+            Self::Assign(name, x) => write!(f, "({} = {:?})", name, *x),
         }
     }
 }
@@ -118,6 +129,11 @@ pub fn evaluate<I: Interrupt>(
                 return Err("Unable to convert value to a function".to_string())?;
             }
         },
+        Expr::Assign(ident, x) => {
+            let value = evaluate(*x)?;
+            scope.insert(ident, value.clone());
+            value
+        }
     })
 }
 
@@ -130,6 +146,24 @@ fn eval<I: Interrupt>(
     crate::eval::evaluate_to_value(input, options, scope, int).map_err(crate::err::IntErr::unwrap)
 }
 
+// Built-in physical constants, as `(name, value expression)`. Unlike `pi`
+// and `e` in `resolve_identifier` below, these carry units, so they can't be represented as a bare
+// `Number` literal, but they're still fixed constants we control: adding a
+// new one only means adding an entry here, and like `pi` and `e` we use
+// `eval` above to build the `Number` so a typo here is our bug, not
+// something that can surface as a user-facing parse error.
+const PHYSICAL_CONSTANTS: &[(&str, &str)] = &[
+    ("c", "299792458 m / s"),
+    ("h", "6.62607015e-34 J s"),
+    ("hbar", "1.0545718176461565e-34 J s"),
+    ("k", "1.380649e-23 J / K"),
+    ("G", "6.6743e-11 m^3 / (kg s^2)"),
+    ("N_A", "6.02214076e23 / mol"),
+    ("e0", "8.8541878128e-12 F / m"),
+    ("mu0", "1.25663706212e-6 N / A^2"),
+    ("q", "1.602176634e-19 C"),
+];
+
 fn resolve_identifier<I: Interrupt>(
     ident: &str,
     scope: &mut Scope,
@@ -153,9 +187,6 @@ fn resolve_identifier<I: Interrupt>(
         "pi" => eval("approx. 3.141592653589793238", scope, int)?,
         "e" => eval("approx. 2.718281828459045235", scope, int)?,
         "i" => Value::Num(Number::i()),
-        // TODO: we want to forward any interrupt, but panic on any other error
-        // or statically prove that no other error can occur
-        //"c" => eval("299792458 m / s", scope, int)?,
         "sqrt" => Value::Func("sqrt"),
         "cbrt" => Value::Func("cbrt"),
         "abs" => Value::Func("abs"),
@@ -186,6 +217,16 @@ fn resolve_identifier<I: Interrupt>(
         "hex" | "hexadecimal" => Value::Base(Base::from_plain_base(16).map_err(|e| e.to_string())?),
         "binary" => Value::Base(Base::from_plain_base(2).map_err(|e| e.to_string())?),
         "octal" => Value::Base(Base::from_plain_base(8).map_err(|e| e.to_string())?),
-        _ => scope.get(ident, int)?,
+        _ => match scope.get(ident, int) {
+            Ok(value) => value,
+            // user-defined bindings shadow the built-in physical constants below
+            Err(IntErr::Interrupt(i)) => return Err(IntErr::Interrupt(i)),
+            Err(IntErr::Error(scope_err)) => {
+                match PHYSICAL_CONSTANTS.iter().find(|(name, _)| *name == ident) {
+                    Some(&(_, expr)) => eval(expr, scope, int)?,
+                    None => return Err(IntErr::Error(scope_err)),
+                }
+            }
+        },
     })
 }
@@ -0,0 +1,34 @@
+use crate::err::IntErr;
+use crate::interrupt::{test_int, Interrupt};
+use crate::value::Value;
+use std::collections::HashMap;
+
+/// User-defined variable and function bindings, e.g. those created by
+/// `x = 5`. Looked up by `resolve_identifier` after the built-in names it
+/// matches directly but before the built-in physical constants, which these
+/// bindings shadow, and mutated by `Expr::Assign`.
+#[derive(Clone, Default)]
+pub struct Scope {
+    variables: HashMap<String, Value>,
+}
+
+impl Scope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get<I: Interrupt>(&self, ident: &str, int: &I) -> Result<Value, IntErr<String, I>> {
+        test_int(int)?;
+        self.variables
+            .get(ident)
+            .cloned()
+            .ok_or_else(|| format!("Unknown identifier '{ident}'"))
+            .map_err(IntErr::Error)
+    }
+
+    /// Bind `ident` to `value` in this scope, overwriting any previous
+    /// binding of the same name.
+    pub fn insert(&mut self, ident: String, value: Value) {
+        self.variables.insert(ident, value);
+    }
+}